@@ -1,7 +1,11 @@
 use std::convert::{TryFrom, TryInto};
+use std::io::{Read, Write};
 
 use anyhow::anyhow;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
+use crate::chunk_io::{AsyncChunkIo, SyncChunkIo};
 use crate::chunk_type::ChunkType;
 use crate::{Error, Result};
 use crate::crc::Crc;
@@ -9,12 +13,15 @@ use crate::crc::Crc;
 #[derive(Debug, Eq, PartialEq)]
 pub struct Chunk {
     chunk_type: ChunkType,
-    data: Vec<u8>,
+    data: Bytes,
 }
 
 impl Chunk {
     pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Self {
-        Self { chunk_type, data }
+        Self {
+            chunk_type,
+            data: Bytes::from(data),
+        }
     }
 
     pub fn length(&self) -> u32 {
@@ -29,6 +36,10 @@ impl Chunk {
         &self.data
     }
 
+    pub fn data_bytes(&self) -> Bytes {
+        self.data.clone()
+    }
+
     pub fn data_as_string(&self) -> Result<String> {
         let str = std::str::from_utf8(&self.data)?;
         Ok(str.to_string())
@@ -42,12 +53,12 @@ impl Chunk {
     }
 
     pub fn as_bytes(&self) -> Vec<u8> {
-        let mut result = Vec::new();
-        result.extend(&self.length().to_be_bytes());
-        result.extend(self.chunk_type.bytes());
-        result.extend(&self.data);
-        result.extend(&self.crc().to_be_bytes());
-        result
+        let mut result = BytesMut::with_capacity(12 + self.data.len());
+        result.put_u32(self.length());
+        result.put_slice(self.chunk_type.bytes());
+        result.put_slice(&self.data);
+        result.put_u32(self.crc());
+        result.to_vec()
     }
 
     pub fn parse_next(value: &[u8]) -> Result<(Self, &[u8])> {
@@ -68,18 +79,22 @@ impl Chunk {
             return Err(anyhow!("Too large length"));
         }
         let length = usize::try_from(length)?;
-        if length + 4 < remaining.len() {
+        if length + 4 > remaining.len() {
             return Err(anyhow!("Too large length, larger than remaining data"));
         }
 
-        // Get the data and the CRC
+        // Get the data and the CRC; only the chunk's own payload is copied
+        // into a Bytes here, not the whole remaining slice, so walking an
+        // n-chunk buffer via parse_next stays O(n) instead of O(n^2).
         let data = &remaining[..length];
         let crc = &remaining[length..length + 4];
         let remaining = &remaining[length + 4..];
         let crc = u32::from_be_bytes(crc.try_into()?);
 
-        // Check that the CRC is valid
-        let chunk = Chunk::new(chunk_type, data.to_vec());
+        let chunk = Self {
+            chunk_type,
+            data: Bytes::copy_from_slice(data),
+        };
         let chunk_crc = chunk.crc();
         if chunk_crc != crc {
             Err(anyhow!("Incorrect chunk CRC: {} != {}", crc, chunk_crc))
@@ -87,6 +102,121 @@ impl Chunk {
             Ok((chunk, remaining))
         }
     }
+
+    // Like `parse_next`, but zero-copy: shares `data`'s backing buffer.
+    pub fn parse_next_buf(data: &mut Bytes) -> Result<Self> {
+        // Get the individual parts as byte slices
+        if data.len() < 12 {
+            return Err(anyhow!("Too short chunk data"));
+        }
+        let length = data.get_u32();
+        let mut chunk_type = [0; 4];
+        data.copy_to_slice(&mut chunk_type);
+        let chunk_type = ChunkType::new(chunk_type)?;
+
+        // Check that the length is valid
+        if length > u32::max_value() - 4 {
+            return Err(anyhow!("Too large length"));
+        }
+        let length = usize::try_from(length)?;
+        if length + 4 > data.len() {
+            return Err(anyhow!("Too large length, larger than remaining data"));
+        }
+
+        // Get the data and the CRC
+        let chunk_data = data.copy_to_bytes(length);
+        let crc = data.get_u32();
+
+        // Check that the CRC is valid
+        let chunk = Self {
+            chunk_type,
+            data: chunk_data,
+        };
+        let chunk_crc = chunk.crc();
+        if chunk_crc != crc {
+            Err(anyhow!("Incorrect chunk CRC: {} != {}", crc, chunk_crc))
+        } else {
+            Ok(chunk)
+        }
+    }
+
+    pub fn read_from<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut header = [0; 8];
+        reader.read_chunk_bytes(&mut header)?;
+        let length = Self::parse_length(&header)?;
+        let chunk_type = ChunkType::new(header[4..8].try_into()?)?;
+
+        let data = Self::read_chunk_data(reader, length)?;
+        let mut crc = [0; 4];
+        reader.read_chunk_bytes(&mut crc)?;
+        let crc = u32::from_be_bytes(crc);
+
+        Self::from_parts(chunk_type, data, crc)
+    }
+
+    pub async fn read_from_async<R: AsyncRead + Unpin + Send>(reader: &mut R) -> Result<Self> {
+        let mut header = [0; 8];
+        reader.read_chunk_bytes(&mut header).await?;
+        let length = Self::parse_length(&header)?;
+        let chunk_type = ChunkType::new(header[4..8].try_into()?)?;
+
+        let data = Self::read_chunk_data_async(reader, length).await?;
+        let mut crc = [0; 4];
+        reader.read_chunk_bytes(&mut crc).await?;
+        let crc = u32::from_be_bytes(crc);
+
+        Self::from_parts(chunk_type, data, crc)
+    }
+
+    // Don't trust `length` (it comes off the wire) as an eager allocation size.
+    fn read_chunk_data<R: Read>(reader: &mut R, length: usize) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        reader.take(length as u64).read_to_end(&mut data)?;
+        if data.len() != length {
+            return Err(anyhow!("Unexpected end of file while reading chunk data"));
+        }
+        Ok(data)
+    }
+
+    async fn read_chunk_data_async<R: AsyncRead + Unpin>(
+        reader: &mut R,
+        length: usize,
+    ) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        reader.take(length as u64).read_to_end(&mut data).await?;
+        if data.len() != length {
+            return Err(anyhow!("Unexpected end of file while reading chunk data"));
+        }
+        Ok(data)
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.as_bytes())?;
+        Ok(())
+    }
+
+    pub async fn write_to_async<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.as_bytes()).await?;
+        Ok(())
+    }
+
+    fn parse_length(header: &[u8; 8]) -> Result<usize> {
+        let length = u32::from_be_bytes(header[0..4].try_into()?);
+        if length > u32::max_value() - 4 {
+            return Err(anyhow!("Too large length"));
+        }
+        Ok(usize::try_from(length)?)
+    }
+
+    fn from_parts(chunk_type: ChunkType, data: Vec<u8>, crc: u32) -> Result<Self> {
+        let chunk = Chunk::new(chunk_type, data);
+        let chunk_crc = chunk.crc();
+        if chunk_crc != crc {
+            Err(anyhow!("Incorrect chunk CRC: {} != {}", crc, chunk_crc))
+        } else {
+            Ok(chunk)
+        }
+    }
 }
 
 impl TryFrom<&[u8]> for Chunk {
@@ -100,6 +230,17 @@ impl TryFrom<&[u8]> for Chunk {
     }
 }
 
+impl TryFrom<Bytes> for Chunk {
+    type Error = Error;
+    fn try_from(mut value: Bytes) -> Result<Self> {
+        let chunk = Self::parse_next_buf(&mut value)?;
+        if !value.is_empty() {
+            return Err(anyhow!("Trailing data left after chunk"));
+        }
+        Ok(chunk)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +308,94 @@ mod tests {
         assert_eq!(chunk.crc(), 2882656334);
     }
 
+    #[test]
+    fn test_chunk_data_bytes_shares_data() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.data_bytes().as_ref(), chunk.data());
+        assert_eq!(chunk.data_bytes().as_ptr(), chunk.data().as_ptr());
+    }
+
+    #[test]
+    fn test_valid_chunk_from_reader() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::read_from(&mut chunk_data.as_slice()).unwrap();
+
+        assert_eq!(chunk.length(), 42);
+        assert_eq!(chunk.chunk_type().to_string(), String::from("RuSt"));
+        assert_eq!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_truncated_chunk_from_reader() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "too short".as_bytes();
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .copied()
+            .collect();
+
+        let err = Chunk::read_from(&mut chunk_data.as_slice()).unwrap_err();
+        assert!(err.to_string().contains("Unexpected end of file"));
+    }
+
+    #[test]
+    fn test_chunk_write_to_round_trips_through_read_from() {
+        let chunk = testing_chunk();
+        let mut buf = Vec::new();
+        chunk.write_to(&mut buf).unwrap();
+
+        let read_back = Chunk::read_from(&mut buf.as_slice()).unwrap();
+        assert_eq!(chunk, read_back);
+    }
+
+    #[tokio::test]
+    async fn test_chunk_write_to_async_round_trips_through_read_from_async() {
+        let chunk = testing_chunk();
+        let mut buf = Vec::new();
+        chunk.write_to_async(&mut buf).await.unwrap();
+
+        let read_back = Chunk::read_from_async(&mut buf.as_slice()).await.unwrap();
+        assert_eq!(chunk, read_back);
+    }
+
+    #[tokio::test]
+    async fn test_truncated_chunk_from_reader_async() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "too short".as_bytes();
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .copied()
+            .collect();
+
+        let err = Chunk::read_from_async(&mut chunk_data.as_slice())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Unexpected end of file"));
+    }
+
     #[test]
     fn test_invalid_chunk_from_bytes() {
         let data_length: u32 = 42;
@@ -0,0 +1,36 @@
+use std::io::{self, ErrorKind, Read};
+
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{Error, Result};
+
+fn map_eof(err: io::Error) -> Error {
+    if err.kind() == ErrorKind::UnexpectedEof {
+        anyhow::anyhow!("Unexpected end of file while reading chunk: {}", err)
+    } else {
+        Error::from(err)
+    }
+}
+
+pub trait SyncChunkIo {
+    fn read_chunk_bytes(&mut self, buf: &mut [u8]) -> Result<()>;
+}
+
+impl<R: Read> SyncChunkIo for R {
+    fn read_chunk_bytes(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.read_exact(buf).map_err(map_eof)
+    }
+}
+
+#[async_trait]
+pub trait AsyncChunkIo {
+    async fn read_chunk_bytes(&mut self, buf: &mut [u8]) -> Result<()>;
+}
+
+#[async_trait]
+impl<R: AsyncRead + Unpin + Send> AsyncChunkIo for R {
+    async fn read_chunk_bytes(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.read_exact(buf).await.map(|_| ()).map_err(map_eof)
+    }
+}
@@ -1,17 +1,49 @@
 // See chapter 15 (Appendix: Sample CRC Code) of the PNG spec
 
-fn crc_table(n: u8) -> u32 {
-    let mut c = u32::from(n);
-    for _ in 0..8 {
-        if c & 1 != 0 {
-            c = 0xedb8_8320 ^ (c >> 1);
-        } else {
-            c = c >> 1;
+use std::convert::TryInto;
+
+// Computed once at compile time instead of per byte at runtime.
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            if c & 1 != 0 {
+                c = 0xedb8_8320 ^ (c >> 1);
+            } else {
+                c >>= 1;
+            }
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+}
+
+// Slicing-by-8: CRC_TABLES[0] is the standard table, CRC_TABLES[k] folds in
+// k more bytes of lookahead so Crc::update can process 8 bytes at a time.
+const fn slicing_tables() -> [[u32; 256]; 8] {
+    let table0 = crc_table();
+    let mut tables = [[0; 256]; 8];
+    tables[0] = table0;
+    let mut k = 1;
+    while k < 8 {
+        let mut i = 0;
+        while i < 256 {
+            let prev = tables[k - 1][i];
+            tables[k][i] = (prev >> 8) ^ table0[(prev & 0xff) as usize];
+            i += 1;
         }
+        k += 1;
     }
-    c
+    tables
 }
 
+const CRC_TABLES: [[u32; 256]; 8] = slicing_tables();
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct Crc(u32);
 
@@ -26,12 +58,26 @@ impl Crc {
 
     pub fn update_byte(&self, byte: u8) -> Self {
         let index = (self.0 ^ u32::from(byte)) & 0xff;
-        Self(crc_table(index as u8) ^ (self.0 >> 8))
+        Self(CRC_TABLES[0][index as usize] ^ (self.0 >> 8))
     }
 
     pub fn update(&self, data: &[u8]) -> Self {
-        let mut crc = *self;
-        for byte in data {
+        let mut crc = self.0;
+        let mut chunks = data.chunks_exact(8);
+        for chunk in &mut chunks {
+            crc ^= u32::from_le_bytes(chunk[0..4].try_into().unwrap());
+            crc = CRC_TABLES[7][(crc & 0xff) as usize]
+                ^ CRC_TABLES[6][((crc >> 8) & 0xff) as usize]
+                ^ CRC_TABLES[5][((crc >> 16) & 0xff) as usize]
+                ^ CRC_TABLES[4][((crc >> 24) & 0xff) as usize]
+                ^ CRC_TABLES[3][chunk[4] as usize]
+                ^ CRC_TABLES[2][chunk[5] as usize]
+                ^ CRC_TABLES[1][chunk[6] as usize]
+                ^ CRC_TABLES[0][chunk[7] as usize];
+        }
+
+        let mut crc = Self(crc);
+        for byte in chunks.remainder() {
             crc = crc.update_byte(*byte);
         }
         crc
@@ -5,6 +5,7 @@ use structopt::StructOpt;
 
 mod args;
 mod chunk;
+mod chunk_io;
 mod chunk_type;
 mod commands;
 mod crc;
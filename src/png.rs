@@ -0,0 +1,184 @@
+use std::convert::TryFrom;
+use std::io::{Read, Write};
+
+use anyhow::anyhow;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::chunk::Chunk;
+use crate::{Error, Result};
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl Png {
+    pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Self {
+        Self { chunks }
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|chunk| chunk.chunk_type().to_string() == chunk_type)
+    }
+
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        Self::STANDARD_HEADER
+            .iter()
+            .copied()
+            .chain(self.chunks.iter().flat_map(Chunk::as_bytes))
+            .collect()
+    }
+
+    pub fn read_from<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut header = [0; 8];
+        reader.read_exact(&mut header)?;
+        if header != Self::STANDARD_HEADER {
+            return Err(anyhow!("Invalid PNG header: {:?}", header));
+        }
+
+        let mut chunks = Vec::new();
+        loop {
+            let chunk = Chunk::read_from(reader)?;
+            let is_end = chunk.chunk_type().to_string() == "IEND";
+            chunks.push(chunk);
+            if is_end {
+                break;
+            }
+        }
+
+        Ok(Self { chunks })
+    }
+
+    pub async fn read_from_async<R: AsyncRead + Unpin + Send>(reader: &mut R) -> Result<Self> {
+        let mut header = [0; 8];
+        reader.read_exact(&mut header).await?;
+        if header != Self::STANDARD_HEADER {
+            return Err(anyhow!("Invalid PNG header: {:?}", header));
+        }
+
+        let mut chunks = Vec::new();
+        loop {
+            let chunk = Chunk::read_from_async(reader).await?;
+            let is_end = chunk.chunk_type().to_string() == "IEND";
+            chunks.push(chunk);
+            if is_end {
+                break;
+            }
+        }
+
+        Ok(Self { chunks })
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&Self::STANDARD_HEADER)?;
+        for chunk in &self.chunks {
+            chunk.write_to(writer)?;
+        }
+        Ok(())
+    }
+
+    pub async fn write_to_async<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&Self::STANDARD_HEADER).await?;
+        for chunk in &self.chunks {
+            chunk.write_to_async(writer).await?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = Error;
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        Self::read_from(&mut reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn testing_chunks() -> Vec<Chunk> {
+        vec![
+            Chunk::new(ChunkType::from_str("FiRs").unwrap(), b"first".to_vec()),
+            Chunk::new(ChunkType::from_str("ScNd").unwrap(), b"second".to_vec()),
+            Chunk::new(ChunkType::from_str("IEND").unwrap(), Vec::new()),
+        ]
+    }
+
+    #[test]
+    fn test_png_read_from_multiple_chunks() {
+        let png = Png::from_chunks(testing_chunks());
+        let bytes = png.as_bytes();
+
+        let read_back = Png::read_from(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(read_back.chunks().len(), 3);
+        assert_eq!(read_back.chunk_by_type("ScNd").unwrap().data(), b"second");
+    }
+
+    #[test]
+    fn test_png_read_from_rejects_invalid_header() {
+        let mut bytes = Png::from_chunks(testing_chunks()).as_bytes();
+        bytes[0] = 0;
+
+        let result = Png::read_from(&mut bytes.as_slice());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_png_write_to_round_trips_through_read_from() {
+        let png = Png::from_chunks(testing_chunks());
+
+        let mut buf = Vec::new();
+        png.write_to(&mut buf).unwrap();
+        let read_back = Png::read_from(&mut buf.as_slice()).unwrap();
+
+        assert_eq!(png, read_back);
+    }
+
+    #[tokio::test]
+    async fn test_png_write_to_async_round_trips_through_read_from_async() {
+        let png = Png::from_chunks(testing_chunks());
+
+        let mut buf = Vec::new();
+        png.write_to_async(&mut buf).await.unwrap();
+        let read_back = Png::read_from_async(&mut buf.as_slice()).await.unwrap();
+
+        assert_eq!(png, read_back);
+    }
+
+    #[tokio::test]
+    async fn test_png_read_from_async_rejects_invalid_header() {
+        let mut bytes = Png::from_chunks(testing_chunks()).as_bytes();
+        bytes[0] = 0;
+
+        let result = Png::read_from_async(&mut bytes.as_slice()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_png_read_from_async_truncated() {
+        let bytes = Png::from_chunks(testing_chunks()).as_bytes();
+        let mut truncated = &bytes[..bytes.len() - 3];
+
+        let err = Png::read_from_async(&mut truncated).await.unwrap_err();
+        assert!(err.to_string().contains("Unexpected end of file"));
+    }
+}